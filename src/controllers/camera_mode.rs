@@ -0,0 +1,149 @@
+use crate::controllers::fps::FpsCameraController;
+use crate::controllers::orbit::OrbitCameraController;
+
+use bevy::{app::prelude::*, ecs::prelude::*, input::prelude::*};
+
+/// Which controller currently drives a shared `LookTransform`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CameraMode {
+    Fps,
+    Orbit,
+}
+
+impl CameraMode {
+    fn next(self) -> Self {
+        match self {
+            CameraMode::Fps => CameraMode::Orbit,
+            CameraMode::Orbit => CameraMode::Fps,
+        }
+    }
+}
+
+/// Requests that a camera cycle to its next mode. `camera_entity` targets a specific camera,
+/// or every `CameraModeController` if `None`.
+#[derive(Event, Message)]
+pub struct CycleCameraModeMessage {
+    pub camera_entity: Option<Entity>,
+}
+
+/// Emitted after a `CameraModeController` switches modes, so games can update HUD state.
+#[derive(Event, Message)]
+pub struct CameraModeChangedMessage {
+    pub camera_entity: Entity,
+    pub mode: CameraMode,
+}
+
+/// Coordinates an entity that carries both an `FpsCameraController` and an
+/// `OrbitCameraController` on top of the same `LookTransform`/`Smoother`, enabling exactly one
+/// of them at a time so switching modes doesn't snap the camera.
+#[derive(Clone, Component, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CameraModeController {
+    pub mode: CameraMode,
+    /// Key that requests a cycle to the next mode.
+    pub key_cycle_mode: KeyCode,
+}
+
+impl Default for CameraModeController {
+    fn default() -> Self {
+        Self {
+            mode: CameraMode::Fps,
+            key_cycle_mode: KeyCode::Tab,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CameraModeControllerPlugin;
+
+impl Plugin for CameraModeControllerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<CycleCameraModeMessage>()
+            .add_message::<CameraModeChangedMessage>()
+            .add_systems(PreUpdate, sync_spawned_camera_mode)
+            .add_systems(
+                Update,
+                (default_cycle_input_map, apply_camera_mode_system).chain(),
+            );
+    }
+}
+
+/// Applies `mode` to the sub-controllers' `enabled` fields, leaving any controller the entity
+/// doesn't have untouched.
+fn apply_mode(
+    mode: CameraMode,
+    fps: Option<Mut<FpsCameraController>>,
+    orbit: Option<Mut<OrbitCameraController>>,
+) {
+    if let Some(mut fps) = fps {
+        fps.enabled = mode == CameraMode::Fps;
+    }
+    if let Some(mut orbit) = orbit {
+        orbit.enabled = mode == CameraMode::Orbit;
+    }
+}
+
+/// Syncs a freshly-spawned `CameraModeController`'s sub-controllers to its starting `mode`
+/// before any `CycleCameraModeMessage` fires, so they don't both stay enabled by default.
+fn sync_spawned_camera_mode(
+    mut cameras: Query<
+        (
+            &CameraModeController,
+            Option<&mut FpsCameraController>,
+            Option<&mut OrbitCameraController>,
+        ),
+        Added<CameraModeController>,
+    >,
+) {
+    for (mode_controller, fps, orbit) in cameras.iter_mut() {
+        apply_mode(mode_controller.mode, fps, orbit);
+    }
+}
+
+/// Default input mapping: pressing `key_cycle_mode` requests a cycle on its own entity.
+fn default_cycle_input_map(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    controllers: Query<(Entity, &CameraModeController)>,
+    mut messages: MessageWriter<CycleCameraModeMessage>,
+) {
+    for (entity, controller) in controllers.iter() {
+        if keyboard.just_pressed(controller.key_cycle_mode) {
+            messages.write(CycleCameraModeMessage {
+                camera_entity: Some(entity),
+            });
+        }
+    }
+}
+
+/// Applies pending cycle requests by enabling exactly one of the FPS/orbit controllers on the
+/// entity and disabling the rest. The shared `LookTransform`/`Smoother` is left untouched, so
+/// the transition is smooth rather than snapping.
+fn apply_camera_mode_system(
+    mut messages: MessageReader<CycleCameraModeMessage>,
+    mut changed: MessageWriter<CameraModeChangedMessage>,
+    mut cameras: Query<(
+        Entity,
+        &mut CameraModeController,
+        Option<&mut FpsCameraController>,
+        Option<&mut OrbitCameraController>,
+    )>,
+) {
+    for message in messages.read() {
+        for (entity, mut mode_controller, fps, orbit) in cameras.iter_mut() {
+            if let Some(target) = message.camera_entity {
+                if target != entity {
+                    continue;
+                }
+            }
+
+            mode_controller.mode = mode_controller.mode.next();
+            apply_mode(mode_controller.mode, fps, orbit);
+
+            changed.write(CameraModeChangedMessage {
+                camera_entity: entity,
+                mode: mode_controller.mode,
+            });
+        }
+    }
+}
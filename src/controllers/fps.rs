@@ -8,7 +8,7 @@ use bevy::{
     math::prelude::*,
     time::Time,
     transform::components::Transform,
-    window::{CursorGrabMode, CursorOptions},
+    window::{CursorGrabMode, CursorIcon, CursorOptions},
 };
 
 /// Defines the cursor toggle mode for the FPS camera
@@ -76,12 +76,13 @@ impl FpsCameraBundle {
     pub fn new(controller: FpsCameraController, eye: Vec3, target: Vec3, up: Vec3) -> Self {
         // Make sure the transform is consistent with the controller to start.
         let transform = Transform::from_translation(eye).looking_at(target, up);
+        let smoother = Smoother::new(controller.smoothing_weight);
 
         Self {
             controller,
             look_transform: LookTransformBundle {
                 transform: LookTransform::new(eye, target, up),
-                smoother: Smoother::new(controller.smoothing_weight),
+                smoother,
             },
             transform,
         }
@@ -89,17 +90,45 @@ impl FpsCameraBundle {
 }
 
 /// Your typical first-person camera controller.
-#[derive(Clone, Component, Copy, Debug)]
+#[derive(Clone, Component, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct FpsCameraController {
     pub enabled: bool,
     pub mouse_rotate_sensitivity: Vec2,
+    /// Eye translation speed while `key_run` is not held. Aliased as the "walk" speed.
     pub translate_sensitivity: f32,
+    /// Eye translation speed while `key_run` is held.
+    pub run_speed: f32,
+    /// Key that, while held, swaps the translation speed from `translate_sensitivity` to `run_speed`.
+    pub key_run: KeyCode,
     pub smoothing_weight: f32,
     /// If set to true, the cursor will be locked and hidden when the camera is active.
     pub auto_hide_cursor: bool,
     /// The mode to use for toggling cursor visibility/locking
     pub cursor_toggle_mode: CursorToggleMode,
+    /// Key that moves the eye forward
+    pub key_forward: KeyCode,
+    /// Key that moves the eye backward
+    pub key_back: KeyCode,
+    /// Key that moves the eye to the left
+    pub key_left: KeyCode,
+    /// Key that moves the eye to the right
+    pub key_right: KeyCode,
+    /// Key that moves the eye up
+    pub key_up: KeyCode,
+    /// Key that moves the eye down
+    pub key_down: KeyCode,
+    /// If set to true, `TranslateEye` messages accumulate into `velocity` and decay over time
+    /// instead of moving the eye instantaneously, giving the camera momentum.
+    pub use_inertia: bool,
+    /// Half-life (in seconds) of the velocity decay used when `use_inertia` is enabled. A
+    /// larger value means the camera coasts for longer before coming to a stop.
+    pub friction: f32,
+    /// Current eye velocity, only meaningful while `use_inertia` is enabled.
+    pub velocity: Vec3,
+    /// If set, this icon is applied to the window while the cursor is grabbed, and the
+    /// window's previous icon is restored when the cursor is released via the Alt toggle.
+    pub grabbed_cursor: Option<CursorIcon>,
 }
 
 impl Default for FpsCameraController {
@@ -108,9 +137,21 @@ impl Default for FpsCameraController {
             enabled: true,
             mouse_rotate_sensitivity: Vec2::splat(0.2),
             translate_sensitivity: 2.0,
+            run_speed: 6.0,
+            key_run: KeyCode::ControlLeft,
             smoothing_weight: 0.9,
             auto_hide_cursor: true,
             cursor_toggle_mode: CursorToggleMode::default(),
+            key_forward: KeyCode::KeyW,
+            key_back: KeyCode::KeyS,
+            key_left: KeyCode::KeyA,
+            key_right: KeyCode::KeyD,
+            key_up: KeyCode::Space,
+            key_down: KeyCode::ShiftLeft,
+            use_inertia: false,
+            friction: 0.2,
+            velocity: Vec3::ZERO,
+            grabbed_cursor: None,
         }
     }
 }
@@ -125,23 +166,75 @@ pub enum ControlMessage {
 #[derive(Component)]
 struct ResetCursorNextFrame;
 
+/// Caches the window's cursor icon from before the FPS camera grabbed it, so it can be
+/// restored when the cursor is released.
+#[derive(Component)]
+struct PreviousCursorIcon(CursorIcon);
+
 define_on_controller_enabled_changed!(FpsCameraController);
 
+/// Applies `grabbed_cursor`'s icon to the window, stashing the icon that was there before so
+/// `restore_previous_icon` can put it back.
+fn apply_grabbed_icon(
+    commands: &mut Commands,
+    camera_entity: Entity,
+    window_entity: Entity,
+    current_icon: Option<&CursorIcon>,
+    grabbed_cursor: &Option<CursorIcon>,
+) {
+    if let Some(icon) = grabbed_cursor {
+        if let Some(prev) = current_icon {
+            commands
+                .entity(camera_entity)
+                .insert(PreviousCursorIcon(prev.clone()));
+        }
+        commands.entity(window_entity).insert(icon.clone());
+    }
+}
+
+/// Restores whatever icon the window had before `grabbed_cursor` was applied.
+fn restore_previous_icon(
+    commands: &mut Commands,
+    camera_entity: Entity,
+    window_entity: Entity,
+    previous_icon: Option<&PreviousCursorIcon>,
+    grabbed_cursor: &Option<CursorIcon>,
+) {
+    if grabbed_cursor.is_some() {
+        if let Some(PreviousCursorIcon(icon)) = previous_icon {
+            commands.entity(window_entity).insert(icon.clone());
+            commands.entity(camera_entity).remove::<PreviousCursorIcon>();
+        } else {
+            commands.entity(window_entity).remove::<CursorIcon>();
+        }
+    }
+}
+
 fn init(
     mut cursor_options: Single<&mut CursorOptions>,
+    window: Single<(Entity, Option<&CursorIcon>), With<PrimaryWindow>>,
     mut commands: Commands,
     cameras: Query<(Entity, &FpsCameraController)>,
 ) {
+    let (window_entity, current_icon) = *window;
+
     // Set initial cursor state
     cursor_options.grab_mode = CursorGrabMode::Locked;
     cursor_options.visible = false;
-    
+
     // Mark cursor to be reset in the next frame for any enabled camera
     for (camera_entity, controller) in cameras.iter() {
         if controller.enabled && controller.auto_hide_cursor {
             cursor_options.visible = false;
             cursor_options.grab_mode = CursorGrabMode::Locked;
             commands.entity(camera_entity).insert(ResetCursorNextFrame);
+            apply_grabbed_icon(
+                &mut commands,
+                camera_entity,
+                window_entity,
+                current_icon,
+                &controller.grabbed_cursor,
+            );
             break; // Only need to do this for one camera
         }
     }
@@ -164,11 +257,25 @@ pub fn default_input_map(
     // Check if cursor is currently locked using the CursorOptions resource
     let cursor_locked = cursor_options.grab_mode == CursorGrabMode::Locked;
     
-    let FpsCameraController {
+    let &FpsCameraController {
         translate_sensitivity,
+        run_speed,
+        key_run,
         mouse_rotate_sensitivity,
+        key_forward,
+        key_back,
+        key_left,
+        key_right,
+        key_up,
+        key_down,
         ..
-    } = *controller;
+    } = controller;
+
+    let translate_sensitivity = if keyboard.pressed(key_run) {
+        run_speed
+    } else {
+        translate_sensitivity
+    };
 
     let mut cursor_delta = Vec2::ZERO;
     // Only process mouse motion if cursor is locked
@@ -183,12 +290,12 @@ pub fn default_input_map(
     ));
 
     for (key, dir) in [
-        (KeyCode::KeyW, Vec3::Z),
-        (KeyCode::KeyA, Vec3::X),
-        (KeyCode::KeyS, -Vec3::Z),
-        (KeyCode::KeyD, -Vec3::X),
-        (KeyCode::ShiftLeft, -Vec3::Y),
-        (KeyCode::Space, Vec3::Y),
+        (key_forward, Vec3::Z),
+        (key_left, Vec3::X),
+        (key_back, -Vec3::Z),
+        (key_right, -Vec3::X),
+        (key_down, -Vec3::Y),
+        (key_up, Vec3::Y),
     ]
     .iter()
     .cloned()
@@ -202,18 +309,23 @@ pub fn default_input_map(
 pub fn control_system(
     mut commands: Commands,
     mut messages: MessageReader<ControlMessage>,
-    mut cameras: Query<(Entity, &FpsCameraController, &mut LookTransform)>,
+    mut cameras: Query<(Entity, &mut FpsCameraController, &mut LookTransform)>,
     mut cursor_options: Single<&mut CursorOptions>,
+    window: Single<(Entity, Option<&CursorIcon>), With<PrimaryWindow>>,
+    previous_icons: Query<&PreviousCursorIcon>,
     key_input: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
 ) {
     // Can only control one camera at a time.
-    let Some((entity, controller, mut transform)) = cameras.iter_mut().find_map(|(e, c, t)| {
+    let Some((entity, mut controller, mut transform)) = cameras.iter_mut().find_map(|(e, c, t)| {
         c.enabled.then_some((e, c, t))
     }) else {
         return;
     };
 
+    let (window_entity, current_icon) = *window;
+    let previous_icon = previous_icons.get(entity).ok();
+
     // Handle cursor locking based on the selected mode
     if controller.auto_hide_cursor {
         match controller.cursor_toggle_mode {
@@ -222,11 +334,25 @@ pub fn control_system(
                     // Release cursor
                     cursor_options.grab_mode = CursorGrabMode::None;
                     cursor_options.visible = true;
+                    restore_previous_icon(
+                        &mut commands,
+                        entity,
+                        window_entity,
+                        previous_icon,
+                        &controller.grabbed_cursor,
+                    );
                 } else if key_input.just_released(KeyCode::AltLeft) || key_input.just_released(KeyCode::AltRight) {
                     // Lock cursor and mark for reset
                     cursor_options.grab_mode = CursorGrabMode::Locked;
                     cursor_options.visible = false;
-                        
+                    apply_grabbed_icon(
+                        &mut commands,
+                        entity,
+                        window_entity,
+                        current_icon,
+                        &controller.grabbed_cursor,
+                    );
+
                     // Mark cursor to be reset in the next frame
                     commands.entity(entity).insert(ResetCursorNextFrame);
                 }
@@ -237,11 +363,25 @@ pub fn control_system(
                         // Release cursor
                         cursor_options.grab_mode = CursorGrabMode::None;
                         cursor_options.visible = true;
+                        restore_previous_icon(
+                            &mut commands,
+                            entity,
+                            window_entity,
+                            previous_icon,
+                            &controller.grabbed_cursor,
+                        );
                     } else {
                         // Lock cursor and mark for reset
                         cursor_options.grab_mode = CursorGrabMode::Locked;
                         cursor_options.visible = false;
-                        
+                        apply_grabbed_icon(
+                            &mut commands,
+                            entity,
+                            window_entity,
+                            current_icon,
+                            &controller.grabbed_cursor,
+                        );
+
                         // Mark cursor to be reset in the next frame
                         commands.entity(entity).insert(ResetCursorNextFrame);
                     }
@@ -268,11 +408,26 @@ pub fn control_system(
             }
             ControlMessage::TranslateEye(delta) => {
                 // Translates up/down (Y) left/right (X) and forward/back (Z).
-                transform.eye += dt * delta.x * rot_x + dt * delta.y * rot_y + dt * delta.z * rot_z;
+                let world_delta = delta.x * rot_x + delta.y * rot_y + delta.z * rot_z;
+                if controller.use_inertia {
+                    controller.velocity += dt * world_delta;
+                } else {
+                    transform.eye += dt * world_delta;
+                }
             }
         }
     }
 
+    if controller.use_inertia {
+        // Exponential decay so the coast feels the same regardless of frame rate.
+        let decay = 0.5_f32.powf(dt / controller.friction.max(1e-4));
+        controller.velocity *= decay;
+        if controller.velocity.length_squared() < 1e-6 {
+            controller.velocity = Vec3::ZERO;
+        }
+        transform.eye += controller.velocity * dt;
+    }
+
     look_angles.assert_not_looking_up();
 
     transform.target = transform.eye + transform.radius() * look_angles.unit_vector();
@@ -0,0 +1,196 @@
+use crate::{LookAngles, LookTransform, LookTransformBundle, Smoother};
+
+use bevy::{
+    app::prelude::*,
+    ecs::prelude::*,
+    input::{
+        mouse::{MouseMotion, MouseWheel},
+        prelude::*,
+    },
+    math::prelude::*,
+    time::Time,
+    transform::components::{GlobalTransform, Transform},
+};
+
+define_on_controller_enabled_changed!(ThirdPersonCameraController);
+
+#[derive(Default)]
+pub struct ThirdPersonCameraPlugin {
+    pub override_input_system: bool,
+}
+
+impl ThirdPersonCameraPlugin {
+    pub fn new(override_input_system: bool) -> Self {
+        Self {
+            override_input_system,
+        }
+    }
+}
+
+impl Plugin for ThirdPersonCameraPlugin {
+    fn build(&self, app: &mut App) {
+        let app = app
+            .add_systems(PreUpdate, on_controller_enabled_changed)
+            .add_systems(Update, control_system)
+            .add_message::<ControlMessage>();
+
+        if !self.override_input_system {
+            app.add_systems(Update, default_input_map);
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ThirdPersonCameraBundle {
+    controller: ThirdPersonCameraController,
+    look_transform: LookTransformBundle,
+    transform: Transform,
+}
+
+impl ThirdPersonCameraBundle {
+    /// `target_translation` is the followed entity's translation at spawn time, used to place
+    /// the eye consistently with the controller's starting distance and look angles.
+    pub fn new(
+        mut controller: ThirdPersonCameraController,
+        target_entity: Entity,
+        target_translation: Vec3,
+        up: Vec3,
+    ) -> Self {
+        controller.target_entity = target_entity;
+
+        let eye = target_translation - controller.distance * controller.look_angles.unit_vector();
+        let transform = Transform::from_translation(eye).looking_at(target_translation, up);
+        let smoother = Smoother::new(controller.smoothing_weight);
+
+        Self {
+            controller,
+            look_transform: LookTransformBundle {
+                transform: LookTransform::new(eye, target_translation, up),
+                smoother,
+            },
+            transform,
+        }
+    }
+}
+
+/// A camera that follows a target entity from a fixed distance and orbiting pitch/yaw offset,
+/// rather than moving a free eye. Reuses the same `LookAngles` math and `Smoother` as the FPS
+/// and orbit controllers.
+#[derive(Clone, Component, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ThirdPersonCameraController {
+    pub enabled: bool,
+    /// The entity this camera follows. Its `GlobalTransform` translation becomes the look target.
+    pub target_entity: Entity,
+    pub mouse_rotate_sensitivity: Vec2,
+    pub mouse_wheel_zoom_sensitivity: f32,
+    /// Mouse button that must be held for mouse motion to orbit the camera.
+    pub orbit_button: MouseButton,
+    pub smoothing_weight: f32,
+    /// Current yaw/pitch of the look direction from the eye toward the target; the eye sits
+    /// `distance` back from the target along this direction.
+    pub look_angles: LookAngles,
+    /// Distance from the eye to the target.
+    pub distance: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+}
+
+impl Default for ThirdPersonCameraController {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            target_entity: Entity::PLACEHOLDER,
+            mouse_rotate_sensitivity: Vec2::splat(0.2),
+            mouse_wheel_zoom_sensitivity: 2.0,
+            orbit_button: MouseButton::Right,
+            smoothing_weight: 0.9,
+            look_angles: LookAngles::default(),
+            distance: 5.0,
+            min_distance: 1.0,
+            max_distance: 20.0,
+        }
+    }
+}
+
+#[derive(Message)]
+pub enum ControlMessage {
+    Orbit(Vec2),
+    Zoom(f32),
+}
+
+pub fn default_input_map(
+    mut messages: MessageWriter<ControlMessage>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion_messages: MessageReader<MouseMotion>,
+    mut mouse_wheel_messages: MessageReader<MouseWheel>,
+    controllers: Query<&ThirdPersonCameraController>,
+) {
+    // Can only control one camera at a time.
+    let controller = if let Some(controller) = controllers.iter().find(|c| c.enabled) {
+        controller
+    } else {
+        return;
+    };
+
+    // Only orbit while the configured mouse button is held, same as a typical orbit camera.
+    let orbiting = mouse_input.pressed(controller.orbit_button);
+
+    let mut cursor_delta = Vec2::ZERO;
+    if orbiting {
+        for event in mouse_motion_messages.read() {
+            cursor_delta += event.delta;
+        }
+    } else {
+        mouse_motion_messages.clear();
+    }
+    messages.write(ControlMessage::Orbit(
+        controller.mouse_rotate_sensitivity * cursor_delta,
+    ));
+
+    let mut wheel_delta = 0.0;
+    for event in mouse_wheel_messages.read() {
+        wheel_delta += event.y;
+    }
+    if wheel_delta != 0.0 {
+        messages.write(ControlMessage::Zoom(
+            controller.mouse_wheel_zoom_sensitivity * -wheel_delta,
+        ));
+    }
+}
+
+pub fn control_system(
+    mut messages: MessageReader<ControlMessage>,
+    mut cameras: Query<(&mut ThirdPersonCameraController, &mut LookTransform)>,
+    targets: Query<&GlobalTransform>,
+    time: Res<Time>,
+) {
+    // Can only control one camera at a time.
+    let Some((mut controller, mut transform)) =
+        cameras.iter_mut().find(|(c, _)| c.enabled)
+    else {
+        return;
+    };
+
+    let Ok(target_transform) = targets.get(controller.target_entity) else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    for event in messages.read() {
+        match event {
+            ControlMessage::Orbit(delta) => {
+                controller.look_angles.add_yaw(dt * -delta.x);
+                controller.look_angles.add_pitch(dt * -delta.y);
+            }
+            ControlMessage::Zoom(delta) => {
+                controller.distance = (controller.distance + delta)
+                    .clamp(controller.min_distance, controller.max_distance);
+            }
+        }
+    }
+
+    let target_translation = target_transform.translation();
+    transform.target = target_translation;
+    transform.eye = target_translation - controller.distance * controller.look_angles.unit_vector();
+}